@@ -10,6 +10,8 @@ use nu_value_ext::{as_string, get_data_by_column_path};
 #[derive(Deserialize)]
 struct SelectArgs {
     rest: Vec<ColumnPath>,
+    strict: bool,
+    compact: bool,
 }
 
 pub struct Select;
@@ -21,10 +23,21 @@ impl WholeStreamCommand for Select {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("select").rest(
-            SyntaxShape::ColumnPath,
-            "the columns to select from the table",
-        )
+        Signature::build("select")
+            .switch(
+                "strict",
+                "fail on missing columns instead of filling them with Nothing",
+                Some('s'),
+            )
+            .switch(
+                "compact",
+                "drops rows where all of the selected columns are empty",
+                Some('c'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "the columns to select from the table",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -47,13 +60,40 @@ impl WholeStreamCommand for Select {
                 example: "ls | select name size",
                 result: None,
             },
+            Example {
+                description: "Select the name column and the size column renamed to bytes",
+                example: "ls | select name size:bytes",
+                result: None,
+            },
+            Example {
+                description: "Error instead of filling in Nothing for a column some rows don't have",
+                example: "ls | select --strict name created_at",
+                result: None,
+            },
+            Example {
+                description: "Drop rows that end up with no value in any of the selected columns",
+                example: "ls | select --compact name target",
+                result: None,
+            },
+            Example {
+                description: "Select a field from an indexed row of a nested table",
+                example: "open data.json | select people.0.name",
+                result: None,
+            },
         ]
     }
 }
 
 async fn select(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (SelectArgs { rest: mut fields }, mut input) = args.process().await?;
+    let (
+        SelectArgs {
+            rest: mut fields,
+            strict,
+            compact,
+        },
+        mut input,
+    ) = args.process().await?;
     if fields.is_empty() {
         return Err(ShellError::labeled_error(
             "Select requires columns to select",
@@ -71,35 +111,34 @@ async fn select(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .cloned()
         .collect::<Vec<ColumnPath>>();
     let mut bring_back: indexmap::IndexMap<String, Vec<Value>> = indexmap::IndexMap::new();
+    let mut existing_columns: indexmap::IndexSet<String> = indexmap::IndexSet::new();
 
     while let Some(value) = input.next().await {
-        for path in &column_paths {
+        if let UntaggedValue::Row(dictionary) = &value.value {
+            for column_name in dictionary.entries.keys() {
+                existing_columns.insert(column_name.clone());
+            }
+        }
+
+        for original_path in &column_paths {
+            let (path, alias) = path_with_alias(original_path);
+
             let fetcher = get_data_by_column_path(
                 &value,
                 &path,
-                move |obj_source, path_member_tried, error| {
-                    if let PathMember {
-                        unspanned: UnspannedPathMember::String(column),
-                        ..
-                    } = path_member_tried
-                    {
-                        return ShellError::labeled_error_with_secondary(
-                        "No data to fetch.",
-                        format!("Couldn't select column \"{}\"", column),
-                        path_member_tried.span,
-                        "How about exploring it with \"get\"? Check the input is appropriate originating from here",
-                        obj_source.tag.span);
-                    }
-
-                    error
+                |obj_source, path_member_tried, error| {
+                    fetch_error(&existing_columns, obj_source, path_member_tried, error)
                 },
             );
 
             let field = path.clone();
-            let key = as_string(
-                &UntaggedValue::Primitive(Primitive::ColumnPath(field.clone()))
-                    .into_untagged_value(),
-            )?;
+            let key = match &alias {
+                Some(alias) => alias.clone(),
+                None => as_string(
+                    &UntaggedValue::Primitive(Primitive::ColumnPath(field.clone()))
+                        .into_untagged_value(),
+                )?,
+            };
 
             match fetcher {
                 Ok(results) => match results.value {
@@ -119,15 +158,7 @@ async fn select(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     }
                 },
                 Err(reason) => {
-                    // At the moment, we can't add switches, named flags
-                    // and the like while already using .rest since it
-                    // breaks the parser.
-                    //
-                    // We allow flexibility for now and skip the error
-                    // if a given column isn't present.
-                    let strict: Option<bool> = None;
-
-                    if strict.is_some() {
+                    if strict {
                         return Err(reason);
                     }
 
@@ -145,31 +176,152 @@ async fn select(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let keys = bring_back.keys().cloned().collect::<Vec<String>>();
 
-    Ok(futures::stream::iter((0..max).map(move |current| {
+    Ok(futures::stream::iter((0..max).filter_map(move |current| {
         let mut out = TaggedDictBuilder::new(name.clone());
+        let mut all_nothing = true;
 
         for k in &keys {
             let nothing = UntaggedValue::Primitive(Primitive::Nothing).into_untagged_value();
             let subsets = bring_back.get(k);
 
-            match subsets {
+            let value = match subsets {
                 Some(set) => match set.get(current) {
-                    Some(row) => out.insert_untagged(k, row.get_data(k).borrow().clone()),
-                    None => out.insert_untagged(k, nothing.clone()),
+                    Some(row) => row.get_data(k).borrow().clone(),
+                    None => nothing.clone(),
                 },
-                None => out.insert_untagged(k, nothing.clone()),
+                None => nothing.clone(),
+            };
+
+            if !is_nothing(&value) {
+                all_nothing = false;
             }
+
+            out.insert_untagged(k, value);
         }
 
-        ReturnSuccess::value(out.into_value())
+        if compact && all_nothing {
+            None
+        } else {
+            Some(ReturnSuccess::value(out.into_value()))
+        }
     }))
     .to_output_stream())
 }
 
+// Lets a trailing path member carry a rename, e.g. `size:bytes`, so the
+// fetch still happens against `size` but the down-selected output is
+// keyed on the alias instead of the stringified column path.
+fn path_with_alias(path: &ColumnPath) -> (ColumnPath, Option<String>) {
+    if let Some((last, rest)) = path.members().split_last() {
+        if let UnspannedPathMember::String(text) = &last.unspanned {
+            if let Some(idx) = text.find(':') {
+                let (real_name, alias) = (&text[..idx], &text[idx + 1..]);
+
+                if !real_name.is_empty() && !alias.is_empty() {
+                    let mut members = rest.to_vec();
+                    members.push(PathMember::string(real_name, last.span));
+
+                    return (ColumnPath::new(members), Some(alias.to_string()));
+                }
+            }
+        }
+    }
+
+    (path.clone(), None)
+}
+
+// Builds the error returned when a column path member can't be fetched
+// from `obj_source`: a "did you mean" suggestion for a missing column,
+// or a bounded "the table only has N rows" message for an out-of-range
+// row index. Falls back to the error `get_data_by_column_path` already
+// produced when neither case applies.
+fn fetch_error(
+    existing_columns: &indexmap::IndexSet<String>,
+    obj_source: &Value,
+    path_member_tried: &PathMember,
+    error: ShellError,
+) -> ShellError {
+    match &path_member_tried.unspanned {
+        UnspannedPathMember::String(column) => {
+            let suggestion = match did_you_mean(existing_columns, column) {
+                Some(closest) => format!("Did you mean '{}'?", closest),
+                None => "How about exploring it with \"get\"? Check the input is appropriate originating from here".to_string(),
+            };
+
+            ShellError::labeled_error_with_secondary(
+                "No data to fetch.",
+                format!("Couldn't select column \"{}\"", column),
+                path_member_tried.span,
+                suggestion,
+                obj_source.tag.span,
+            )
+        }
+        UnspannedPathMember::Int(row) => match &obj_source.value {
+            UntaggedValue::Table(rows) => ShellError::labeled_error_with_secondary(
+                "Row not found",
+                format!(
+                    "the table only has {} rows (0..{})",
+                    rows.len(),
+                    rows.len().saturating_sub(1)
+                ),
+                path_member_tried.span,
+                format!("There is no row at index {}", row),
+                obj_source.tag.span,
+            ),
+            _ => error,
+        },
+    }
+}
+
+fn is_nothing(value: &Value) -> bool {
+    matches!(value.value, UntaggedValue::Primitive(Primitive::Nothing))
+}
+
+fn did_you_mean(existing_columns: &indexmap::IndexSet<String>, tried: &str) -> Option<String> {
+    let threshold = std::cmp::max(tried.chars().count() / 2, 1);
+
+    existing_columns
+        .iter()
+        .map(|candidate| (levenshtein_distance(tried, candidate), candidate))
+        .min_by_key(|(distance, _)| *distance)
+        .filter(|(distance, _)| *distance <= threshold)
+        .map(|(_, candidate)| candidate.clone())
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        distances[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            distances[i][j] = std::cmp::min(
+                std::cmp::min(distances[i - 1][j] + 1, distances[i][j - 1] + 1),
+                distances[i - 1][j - 1] + cost,
+            );
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Select;
+    use super::{
+        did_you_mean, fetch_error, is_nothing, levenshtein_distance, path_with_alias, Select,
+    };
     use super::ShellError;
+    use nu_protocol::{ColumnPath, Dictionary, PathMember, Primitive, UnspannedPathMember, UntaggedValue};
+    use nu_source::{Span, Tag};
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -177,4 +329,208 @@ mod tests {
 
         Ok(test_examples(Select {})?)
     }
+
+    #[test]
+    fn nothing_is_nothing() {
+        let nothing = UntaggedValue::Primitive(Primitive::Nothing).into_untagged_value();
+
+        assert!(is_nothing(&nothing));
+    }
+
+    #[test]
+    fn falsy_values_are_not_nothing() {
+        let falsy_boolean = UntaggedValue::Primitive(Primitive::Boolean(false)).into_untagged_value();
+        let zero = UntaggedValue::Primitive(Primitive::Int(0.into())).into_untagged_value();
+        let empty_string = UntaggedValue::Primitive(Primitive::String("".to_string())).into_untagged_value();
+
+        assert!(!is_nothing(&falsy_boolean));
+        assert!(!is_nothing(&zero));
+        assert!(!is_nothing(&empty_string));
+    }
+
+    #[test]
+    fn out_of_range_row_index_reports_table_bounds() {
+        let table = UntaggedValue::Table(vec![
+            UntaggedValue::Primitive(Primitive::Int(1.into())).into_untagged_value(),
+            UntaggedValue::Primitive(Primitive::Int(2.into())).into_untagged_value(),
+        ])
+        .into_untagged_value();
+        let tried = PathMember::int(5, Span::unknown());
+        let existing_columns = indexmap::IndexSet::new();
+        let fallback = ShellError::labeled_error("fallback", "fallback", Tag::unknown());
+
+        let error = fetch_error(&existing_columns, &table, &tried, fallback);
+
+        assert!(error.to_string().contains("2 rows (0..1)"));
+    }
+
+    #[test]
+    fn non_table_source_falls_back_to_original_error() {
+        let not_a_table = UntaggedValue::Primitive(Primitive::Int(0.into())).into_untagged_value();
+        let tried = PathMember::int(0, Span::unknown());
+        let existing_columns = indexmap::IndexSet::new();
+        let fallback = ShellError::labeled_error("fallback label", "fallback", Tag::unknown());
+
+        let error = fetch_error(&existing_columns, &not_a_table, &tried, fallback);
+
+        assert!(error.to_string().contains("fallback label"));
+    }
+
+    #[test]
+    fn missing_column_error_wires_in_the_did_you_mean_suggestion() {
+        let row = UntaggedValue::Row(Dictionary {
+            entries: indexmap::indexmap! {
+                "name".to_string() => UntaggedValue::Primitive(Primitive::Int(1.into())).into_untagged_value(),
+            },
+        })
+        .into_untagged_value();
+        let tried = PathMember::string("nam", Span::unknown());
+        let mut existing_columns = indexmap::IndexSet::new();
+        existing_columns.insert("name".to_string());
+        let fallback = ShellError::labeled_error("fallback", "fallback", Tag::unknown());
+
+        let error = fetch_error(&existing_columns, &row, &tried, fallback);
+
+        assert!(error.to_string().contains("Did you mean 'name'?"));
+    }
+
+    #[test]
+    fn missing_column_error_falls_back_to_the_generic_hint_without_a_suggestion() {
+        let row = UntaggedValue::Row(Dictionary {
+            entries: indexmap::IndexMap::new(),
+        })
+        .into_untagged_value();
+        let tried = PathMember::string("name", Span::unknown());
+        let existing_columns = indexmap::IndexSet::new();
+        let fallback = ShellError::labeled_error("fallback", "fallback", Tag::unknown());
+
+        let error = fetch_error(&existing_columns, &row, &tried, fallback);
+
+        assert!(error.to_string().contains("How about exploring it with \"get\"?"));
+    }
+
+    #[test]
+    fn levenshtein_distance_of_equal_strings_is_zero() {
+        assert_eq!(levenshtein_distance("name", "name"), 0);
+    }
+
+    #[test]
+    fn levenshtein_distance_counts_edits() {
+        assert_eq!(levenshtein_distance("size", "sizee"), 1);
+        assert_eq!(levenshtein_distance("size", "sizes"), 1);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn levenshtein_distance_counts_codepoints_not_bytes() {
+        // "café" (4 codepoints, 5 bytes) vs "cafe" (4 codepoints, 4 bytes):
+        // a byte-wise distance would be thrown off by the multi-byte 'é'.
+        assert_eq!(levenshtein_distance("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn did_you_mean_has_no_suggestion_for_an_empty_candidate_set() {
+        let existing_columns = indexmap::IndexSet::new();
+
+        assert_eq!(did_you_mean(&existing_columns, "name"), None);
+    }
+
+    #[test]
+    fn did_you_mean_suggests_an_exact_match() {
+        let mut existing_columns = indexmap::IndexSet::new();
+        existing_columns.insert("name".to_string());
+        existing_columns.insert("size".to_string());
+
+        assert_eq!(
+            did_you_mean(&existing_columns, "name"),
+            Some("name".to_string())
+        );
+    }
+
+    #[test]
+    fn did_you_mean_suggests_within_threshold() {
+        let mut existing_columns = indexmap::IndexSet::new();
+        existing_columns.insert("name".to_string());
+
+        // "nam" -> "name" is a single-edit away, within half of "nam"'s length.
+        assert_eq!(
+            did_you_mean(&existing_columns, "nam"),
+            Some("name".to_string())
+        );
+    }
+
+    #[test]
+    fn did_you_mean_rejects_beyond_threshold() {
+        let mut existing_columns = indexmap::IndexSet::new();
+        existing_columns.insert("size".to_string());
+
+        // "a" is 4 edits away from "size", well beyond half of "a"'s length.
+        assert_eq!(did_you_mean(&existing_columns, "a"), None);
+    }
+
+    fn member_strings(path: &ColumnPath) -> Vec<String> {
+        path.members()
+            .iter()
+            .map(|member| match &member.unspanned {
+                UnspannedPathMember::String(s) => s.clone(),
+                UnspannedPathMember::Int(i) => i.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn alias_is_not_applied_to_a_non_string_last_member() {
+        let path = ColumnPath::new(vec![PathMember::int(2, Span::unknown())]);
+
+        let (path, alias) = path_with_alias(&path);
+
+        assert_eq!(alias, None);
+        assert_eq!(member_strings(&path), vec!["2".to_string()]);
+    }
+
+    #[test]
+    fn alias_splits_name_and_rename_on_colon() {
+        let path = ColumnPath::new(vec![PathMember::string("size:bytes", Span::unknown())]);
+
+        let (path, alias) = path_with_alias(&path);
+
+        assert_eq!(alias, Some("bytes".to_string()));
+        assert_eq!(member_strings(&path), vec!["size".to_string()]);
+    }
+
+    #[test]
+    fn empty_real_name_is_not_treated_as_an_alias() {
+        let path = ColumnPath::new(vec![PathMember::string(":bytes", Span::unknown())]);
+
+        let (path, alias) = path_with_alias(&path);
+
+        assert_eq!(alias, None);
+        assert_eq!(member_strings(&path), vec![":bytes".to_string()]);
+    }
+
+    #[test]
+    fn empty_alias_is_not_treated_as_an_alias() {
+        let path = ColumnPath::new(vec![PathMember::string("size:", Span::unknown())]);
+
+        let (path, alias) = path_with_alias(&path);
+
+        assert_eq!(alias, None);
+        assert_eq!(member_strings(&path), vec!["size:".to_string()]);
+    }
+
+    #[test]
+    fn alias_preserves_the_rest_of_a_multi_segment_path() {
+        let path = ColumnPath::new(vec![
+            PathMember::string("host", Span::unknown()),
+            PathMember::string("name:hostname", Span::unknown()),
+        ]);
+
+        let (path, alias) = path_with_alias(&path);
+
+        assert_eq!(alias, Some("hostname".to_string()));
+        assert_eq!(
+            member_strings(&path),
+            vec!["host".to_string(), "name".to_string()]
+        );
+    }
 }